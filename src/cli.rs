@@ -17,6 +17,33 @@ pub struct Cli {
     pub parallelism: usize,
 
     /// Download rate limit in bytes per second (optional)
-    #[arg(short = 'r', long, default_value = None)]
+    #[arg(short = 'r', long, default_value = None, value_parser = clap::value_parser!(u64).range(1..))]
     pub rate_limit: Option<u64>,
+
+    /// Disable resume support (enabled by default) and always restart downloads from scratch
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub no_resume: bool,
+
+    /// Maximum number of simultaneous downloads per host, regardless of overall parallelism
+    #[arg(long, default_value = "6", value_parser = clap::value_parser!(usize).range(1..))]
+    pub host_limit: usize,
+
+    /// Maximum number of retry attempts for a transient failure (timeouts, connection errors, HTTP 5xx/429)
+    #[arg(long, default_value = "3")]
+    pub max_retries: u32,
+
+    /// Base delay in milliseconds for the exponential backoff between retries
+    #[arg(long, default_value = "500")]
+    pub retry_base_delay_ms: u64,
+
+    /// Skip files whose inventory `last_data_change` matches the download manifest from a previous run
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub skip_unchanged: bool,
+}
+
+impl Cli {
+    /// Whether downloads should resume from an existing `.part` file.
+    pub fn resume_enabled(&self) -> bool {
+        !self.no_resume
+    }
 }
@@ -1,26 +1,61 @@
 use crate::error::DownloaderError;
+use crate::manifest::Manifest;
+use crate::rate_limiter::RateLimiter;
 use crate::types::{DownloadTask, DownloadReport, DownloadStatus, DownloadSummary, FileFormat, InventoryEntry};
+use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use log::{info, error};
+use rand::Rng;
+use std::collections::HashMap;
+use std::future::Future;
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
+use tokio::sync::Semaphore;
 use tokio::time::sleep;
 
+/// Upper bound on the exponential backoff delay between retries.
+const MAX_RETRY_DELAY_MS: u64 = 30_000;
+
+enum ResumePlan {
+    Restart,
+    Resume(u64),
+    AlreadyComplete(u64),
+}
+
 pub struct Downloader {
     parallelism: usize,
     client: reqwest::Client,
-    rate_limit: Option<u64>,
+    rate_limiter: Option<RateLimiter>,
+    resume: bool,
+    host_limit: usize,
+    max_retries: u32,
+    retry_base_delay_ms: u64,
+    skip_unchanged: bool,
 }
 
 impl Downloader {
-    pub fn new(parallelism: usize, rate_limit: Option<u64>) -> Self {
+    pub fn new(
+        parallelism: usize,
+        rate_limit: Option<u64>,
+        resume: bool,
+        host_limit: usize,
+        max_retries: u32,
+        retry_base_delay_ms: u64,
+        skip_unchanged: bool,
+    ) -> Self {
         Self {
             parallelism,
             client: reqwest::Client::new(),
-            rate_limit,
+            rate_limiter: rate_limit.map(RateLimiter::new),
+            resume,
+            host_limit,
+            max_retries,
+            retry_base_delay_ms,
+            skip_unchanged,
         }
     }
 
@@ -33,21 +68,53 @@ impl Downloader {
         let tasks = self.create_download_tasks(inventory, &output_dir);
         let start_time = Instant::now();
         let mut reports = Vec::new();
-        
-        let chunks: Vec<_> = tasks.chunks(self.parallelism).map(|c| c.to_vec()).collect();
-        
-        for chunk in chunks {
-            let handles: Vec<_> = chunk
-                .into_iter()
-                .map(|task| {
-                    let client = self.client.clone();
-                    let pb = m.add(self.create_progress_bar(&task));
-                    self.download_file(client, task, pb)
-                })
-                .collect();
-
-            let chunk_results = futures::future::join_all(handles).await;
-            reports.extend(chunk_results);
+
+        let mut manifest = if self.skip_unchanged {
+            Some(Manifest::load(&output_dir).await?)
+        } else {
+            None
+        };
+
+        let tasks = match &manifest {
+            Some(manifest) => {
+                let mut downloadable = Vec::new();
+                for task in tasks {
+                    if manifest.is_unchanged(&task).await {
+                        info!("Skipping unchanged file: {}", task.output_path.display());
+                        reports.push(Self::skipped_report(task));
+                    } else {
+                        downloadable.push(task);
+                    }
+                }
+                downloadable
+            }
+            None => tasks,
+        };
+
+        let host_semaphores = self.build_host_semaphores(&tasks);
+        let mut pending = tasks.into_iter();
+        let mut in_flight = FuturesUnordered::new();
+
+        for task in pending.by_ref().take(self.parallelism) {
+            in_flight.push(self.spawn_download(&m, &host_semaphores, task));
+        }
+
+        while let Some(report) = in_flight.next().await {
+            reports.push(report);
+            if let Some(task) = pending.next() {
+                in_flight.push(self.spawn_download(&m, &host_semaphores, task));
+            }
+        }
+
+        if let Some(manifest) = &mut manifest {
+            for report in &reports {
+                if report.status == DownloadStatus::Success {
+                    manifest.record(&report.task);
+                }
+            }
+            if let Err(e) = manifest.save().await {
+                error!("Failed to write download manifest: {}", e);
+            }
         }
 
         let total_duration = start_time.elapsed();
@@ -55,7 +122,10 @@ impl Downloader {
         let successful_downloads = reports.iter()
             .filter(|r| r.status == DownloadStatus::Success)
             .count();
-        let failed_downloads = total_downloads - successful_downloads;
+        let skipped_downloads = reports.iter()
+            .filter(|r| r.status == DownloadStatus::Skipped)
+            .count();
+        let failed_downloads = total_downloads - successful_downloads - skipped_downloads;
         let total_bytes_downloaded: u64 = reports.iter()
             .map(|r| r.bytes_downloaded)
             .sum();
@@ -64,6 +134,7 @@ impl Downloader {
             total_downloads,
             successful_downloads,
             failed_downloads,
+            skipped_downloads,
             total_bytes_downloaded,
             total_duration,
             reports,
@@ -76,64 +147,95 @@ impl Downloader {
         Ok(summary)
     }
 
+    fn skipped_report(task: DownloadTask) -> DownloadReport {
+        DownloadReport {
+            task,
+            status: DownloadStatus::Skipped,
+            bytes_downloaded: 0,
+            duration: Duration::from_secs(0),
+            error: None,
+            attempts: 0,
+        }
+    }
+
+    /// Builds one semaphore per distinct host, each gated at `self.host_limit` permits.
+    fn build_host_semaphores(&self, tasks: &[DownloadTask]) -> HashMap<String, Arc<Semaphore>> {
+        let mut semaphores = HashMap::new();
+        for task in tasks {
+            semaphores
+                .entry(Self::host_key(&task.url))
+                .or_insert_with(|| Arc::new(Semaphore::new(self.host_limit)));
+        }
+        semaphores
+    }
+
+    fn host_key(url: &str) -> String {
+        reqwest::Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(|h| h.to_string()))
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    fn spawn_download<'a>(
+        &'a self,
+        m: &MultiProgress,
+        host_semaphores: &HashMap<String, Arc<Semaphore>>,
+        task: DownloadTask,
+    ) -> impl Future<Output = DownloadReport> + 'a {
+        let client = self.client.clone();
+        let pb = m.add(self.create_progress_bar(&task));
+        let host_semaphore = host_semaphores
+            .get(&Self::host_key(&task.url))
+            .expect("a semaphore is created for every task's host")
+            .clone();
+        self.download_file(client, task, pb, host_semaphore)
+    }
+
     async fn download_file(
         &self,
         client: reqwest::Client,
         task: DownloadTask,
         pb: ProgressBar,
+        host_semaphore: Arc<Semaphore>,
     ) -> DownloadReport {
         let start_time = Instant::now();
-        info!("Starting download: {}", task.url);
-        let mut downloaded: u64 = 0;
-
-        let result = async {
-            let resp = client
-                .get(&task.url)
-                .send()
-                .await
-                .map_err(|e| DownloaderError::RequestError(e))?;
-
-            if !resp.status().is_success() {
-                return Err(DownloaderError::DownloadError(format!(
-                    "HTTP error: {} for URL: {}", 
-                    resp.status(),
-                    task.url
-                )));
-            }
+        let part_path = Self::part_path(&task.output_path);
 
-            let total_size = resp.content_length().unwrap_or(0);
-            pb.set_length(total_size);
+        let _permit = host_semaphore
+            .acquire_owned()
+            .await
+            .expect("host semaphore is never closed");
 
-            let mut file = File::create(&task.output_path)
-                .await
-                .map_err(|e| DownloaderError::IoError(e))?;
+        let mut attempts: u32 = 0;
+        let mut downloaded: u64 = 0;
 
-            let mut stream = resp.bytes_stream();
+        let result = loop {
+            attempts += 1;
+            info!("Starting download (attempt {}): {}", attempts, task.url);
 
-            while let Some(chunk) = stream.next().await {
-                let chunk = chunk.map_err(|e| DownloaderError::DownloadError(e.to_string()))?;
-                
-                if let Some(rate_limit) = self.rate_limit {
-                    let chunk_size = chunk.len() as u64;
-                    let delay = std::time::Duration::from_secs_f64(
-                        chunk_size as f64 / rate_limit as f64
+            match self.try_download(&client, &task, &pb, &part_path).await {
+                Ok(bytes) => {
+                    downloaded = bytes;
+                    break Ok(());
+                }
+                Err(e) if attempts <= self.max_retries && Self::is_retryable(&e) => {
+                    let delay = self.backoff_delay(attempts);
+                    error!(
+                        "Retryable error downloading {} (attempt {} of {}): {}. Retrying in {:?}",
+                        task.url,
+                        attempts,
+                        self.max_retries + 1,
+                        e,
+                        delay
                     );
-                    info!("Rate limiting: chunk_size={} bytes, delay={:?}, rate={} bytes/s", 
-                        chunk_size, delay, rate_limit);
                     sleep(delay).await;
                 }
-                
-                file.write_all(&chunk)
-                    .await
-                    .map_err(|e| DownloaderError::IoError(e))?;
-                
-                downloaded += chunk.len() as u64;
-                pb.set_position(downloaded);
-                pb.set_message(format!("Downloading: {}", task.output_path.display()));
+                Err(e) => {
+                    downloaded = Self::existing_file_len(&part_path).await;
+                    break Err(e);
+                }
             }
-
-            Ok(())
-        }.await;
+        };
 
         let duration = start_time.elapsed();
         let (status, error) = match result {
@@ -143,7 +245,7 @@ impl Downloader {
             },
             Err(e) => {
                 let error_msg = e.to_string();
-                error!("Download failed for {}: {}", task.url, error_msg);
+                error!("Download failed for {} after {} attempt(s): {}", task.url, attempts, error_msg);
                 pb.finish_with_message("Download failed");
                 (DownloadStatus::Failed, Some(error_msg))
             }
@@ -155,6 +257,184 @@ impl Downloader {
             bytes_downloaded: downloaded,
             duration,
             error,
+            attempts,
+        }
+    }
+
+    /// Performs a single download attempt and returns the total bytes on
+    /// disk once the file has been streamed and renamed into place.
+    async fn try_download(
+        &self,
+        client: &reqwest::Client,
+        task: &DownloadTask,
+        pb: &ProgressBar,
+        part_path: &Path,
+    ) -> Result<u64, DownloaderError> {
+        let resume_plan = if self.resume {
+            self.resume_plan(&task.url, part_path, client).await
+        } else {
+            ResumePlan::Restart
+        };
+
+        if let ResumePlan::AlreadyComplete(len) = resume_plan {
+            info!("{} is already fully downloaded, finishing up", task.url);
+            pb.set_length(len);
+            pb.set_position(len);
+            tokio::fs::rename(part_path, &task.output_path)
+                .await
+                .map_err(|e| DownloaderError::IoError(e))?;
+            return Ok(len);
+        }
+
+        let resume_offset = match resume_plan {
+            ResumePlan::Resume(offset) => offset,
+            ResumePlan::Restart | ResumePlan::AlreadyComplete(_) => 0,
+        };
+
+        let mut request = client.get(&task.url);
+        if resume_offset > 0 {
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", resume_offset));
+        }
+
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| DownloaderError::RequestError(e))?;
+
+        let status = resp.status();
+        if resume_offset > 0 && status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            info!("{} reported the range as already satisfied, finishing up", task.url);
+            let len = Self::existing_file_len(part_path).await;
+            tokio::fs::rename(part_path, &task.output_path)
+                .await
+                .map_err(|e| DownloaderError::IoError(e))?;
+            return Ok(len);
+        }
+        if !status.is_success() && status != reqwest::StatusCode::PARTIAL_CONTENT {
+            return Err(DownloaderError::HttpStatus {
+                status,
+                url: task.url.clone(),
+            });
+        }
+
+        let is_resuming = resume_offset > 0 && status == reqwest::StatusCode::PARTIAL_CONTENT;
+        let mut downloaded = if is_resuming { resume_offset } else { 0 };
+
+        let total_size = downloaded + resp.content_length().unwrap_or(0);
+        pb.set_length(total_size);
+        pb.set_position(downloaded);
+
+        let mut file = if is_resuming {
+            info!("Resuming download of {} from byte {}", task.url, resume_offset);
+            tokio::fs::OpenOptions::new()
+                .append(true)
+                .open(part_path)
+                .await
+                .map_err(|e| DownloaderError::IoError(e))?
+        } else {
+            if resume_offset > 0 {
+                info!("Server ignored range request for {}, restarting from scratch", task.url);
+            }
+            File::create(part_path)
+                .await
+                .map_err(|e| DownloaderError::IoError(e))?
+        };
+
+        let mut stream = resp.bytes_stream();
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| DownloaderError::DownloadError(e.to_string()))?;
+
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.acquire(chunk.len() as u64).await;
+            }
+
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| DownloaderError::IoError(e))?;
+
+            downloaded += chunk.len() as u64;
+            pb.set_position(downloaded);
+            pb.set_message(format!("Downloading: {}", task.output_path.display()));
+        }
+
+        file.flush().await.map_err(|e| DownloaderError::IoError(e))?;
+
+        tokio::fs::rename(part_path, &task.output_path)
+            .await
+            .map_err(|e| DownloaderError::IoError(e))?;
+
+        Ok(downloaded)
+    }
+
+    /// Transient failures (connection resets, timeouts, HTTP 5xx/429) are
+    /// worth retrying; everything else is fatal.
+    fn is_retryable(error: &DownloaderError) -> bool {
+        match error {
+            DownloaderError::HttpStatus { status, .. } => {
+                status.is_server_error() || status.as_u16() == 429
+            }
+            DownloaderError::RequestError(e) => e.is_timeout() || e.is_connect() || e.is_body(),
+            DownloaderError::DownloadError(_) => true,
+            DownloaderError::IoError(_) | DownloaderError::ParseError(_) | DownloaderError::CsvError(_) => false,
+        }
+    }
+
+    /// `retry_base_delay_ms * 2^(attempt-1)` plus jitter, capped at `MAX_RETRY_DELAY_MS`.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let backoff = self.retry_base_delay_ms.saturating_mul(1u64 << exponent);
+        let jitter = rand::thread_rng().gen_range(0..=self.retry_base_delay_ms.max(1));
+        Duration::from_millis(backoff.saturating_add(jitter).min(MAX_RETRY_DELAY_MS))
+    }
+
+    async fn existing_file_len(path: &Path) -> u64 {
+        tokio::fs::metadata(path).await.map(|m| m.len()).unwrap_or(0)
+    }
+
+    /// Path of the temporary file a download is streamed into before being
+    /// renamed to `output_path`.
+    fn part_path(output_path: &Path) -> PathBuf {
+        let mut part = output_path.as_os_str().to_os_string();
+        part.push(".part");
+        PathBuf::from(part)
+    }
+
+    /// Checks an existing `.part` file against a HEAD request for `url` to
+    /// decide whether to resume, treat it as already complete, or restart.
+    async fn resume_plan(&self, url: &str, part_path: &Path, client: &reqwest::Client) -> ResumePlan {
+        let existing_len = Self::existing_file_len(part_path).await;
+        if existing_len == 0 {
+            return ResumePlan::Restart;
+        }
+
+        let head = match client.head(url).send().await {
+            Ok(resp) => resp,
+            Err(e) => {
+                info!("HEAD request failed for {}, will not resume: {}", url, e);
+                return ResumePlan::Restart;
+            }
+        };
+
+        let supports_ranges = head.headers()
+            .get(reqwest::header::ACCEPT_RANGES)
+            .map(|v| v.as_bytes() == b"bytes")
+            .unwrap_or(false);
+
+        if !supports_ranges {
+            return ResumePlan::Restart;
+        }
+
+        match head.content_length() {
+            Some(total) if existing_len > total => {
+                info!(
+                    "Local part file for {} ({} bytes) is larger than the remote Content-Length ({} bytes); restarting",
+                    url, existing_len, total
+                );
+                ResumePlan::Restart
+            }
+            Some(total) if existing_len == total => ResumePlan::AlreadyComplete(existing_len),
+            _ => ResumePlan::Resume(existing_len),
         }
     }
 
@@ -235,6 +515,7 @@ impl Downloader {
             "Duration (s)",
             "Speed (MB/s)",
             "URL",
+            "Attempts",
             "Error"
         ])?;
 
@@ -254,6 +535,7 @@ impl Downloader {
                 format!("{:.2}", duration_secs),
                 format!("{:.2}", speed_mbs),
                 report.task.url.clone(),
+                report.attempts.to_string(),
                 report.error.clone().unwrap_or_default()
             ])?;
         }
@@ -8,6 +8,12 @@ pub enum DownloaderError {
     #[error("Download failed: {0}")]
     DownloadError(String),
 
+    #[error("HTTP error: {status} for URL: {url}")]
+    HttpStatus {
+        status: reqwest::StatusCode,
+        url: String,
+    },
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
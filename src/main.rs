@@ -1,7 +1,9 @@
 mod cli;
 mod downloader;
 mod error;
+mod manifest;
 mod parser;
+mod rate_limiter;
 mod types;
 
 use cli::Cli;
@@ -32,7 +34,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
-    let downloader = downloader::Downloader::new(cli.parallelism, cli.rate_limit);
+    let downloader = downloader::Downloader::new(
+        cli.parallelism,
+        cli.rate_limit,
+        cli.resume_enabled(),
+        cli.host_limit,
+        cli.max_retries,
+        cli.retry_base_delay_ms,
+        cli.skip_unchanged,
+    );
     match downloader.download_all(inventory, cli.output_dir).await {
         Ok(summary) => {
             println!("\n{}", "Download Summary:".bold());
@@ -40,9 +50,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Success rate: {:.1}% ({} files)", 
                 (summary.successful_downloads as f64 / summary.total_downloads as f64) * 100.0,
                 summary.successful_downloads.to_string().green());
-            println!("Failure rate: {:.1}% ({} files)", 
+            println!("Failure rate: {:.1}% ({} files)",
                 (summary.failed_downloads as f64 / summary.total_downloads as f64) * 100.0,
                 summary.failed_downloads.to_string().red());
+            if summary.skipped_downloads > 0 {
+                println!("Skipped (unchanged): {} files", summary.skipped_downloads.to_string().yellow());
+            }
             println!("Total data transferred: {:.2} MB", summary.total_bytes_downloaded as f64 / 1_048_576.0);
             println!("Total duration: {:.2?}", summary.total_duration);
             println!("Average speed: {:.2} MB/s", 
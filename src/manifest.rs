@@ -0,0 +1,104 @@
+use crate::error::DownloaderError;
+use crate::types::DownloadTask;
+use csv::{ReaderBuilder, WriterBuilder};
+use log::info;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Inventory timestamps recorded for a file at download time.
+#[derive(Debug, Clone)]
+pub struct ManifestEntry {
+    pub last_data_change: String,
+    pub last_structural_change: String,
+}
+
+/// Sidecar manifest, persisted as `download_manifest.csv` in the output directory.
+pub struct Manifest {
+    path: PathBuf,
+    entries: HashMap<String, ManifestEntry>,
+}
+
+impl Manifest {
+    /// Key a task is stored/looked up under, derived from its output filename.
+    pub fn key_for(task: &DownloadTask) -> String {
+        task.output_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| format!("{}_{}", task.entry.code, task.entry.entry_type))
+    }
+
+    pub fn path(output_dir: &Path) -> PathBuf {
+        output_dir.join("download_manifest.csv")
+    }
+
+    /// Loads the manifest from `output_dir`, or starts an empty one if none exists yet.
+    pub async fn load(output_dir: &Path) -> Result<Self, DownloaderError> {
+        let path = Self::path(output_dir);
+        let mut entries = HashMap::new();
+
+        if path.exists() {
+            let contents = tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| DownloaderError::IoError(e))?;
+            let mut reader = ReaderBuilder::new().from_reader(contents.as_bytes());
+
+            for result in reader.records() {
+                let record = result.map_err(|e| DownloaderError::CsvError(e))?;
+                if let (Some(key), Some(last_data_change), Some(last_structural_change)) =
+                    (record.get(0), record.get(1), record.get(2))
+                {
+                    entries.insert(
+                        key.to_string(),
+                        ManifestEntry {
+                            last_data_change: last_data_change.to_string(),
+                            last_structural_change: last_structural_change.to_string(),
+                        },
+                    );
+                }
+            }
+
+            info!("Loaded download manifest with {} entries from {}", entries.len(), path.display());
+        }
+
+        Ok(Self { path, entries })
+    }
+
+    /// Whether `task` can be skipped: its `last_data_change` must match what's
+    /// recorded, and the previously downloaded file must still exist.
+    pub async fn is_unchanged(&self, task: &DownloadTask) -> bool {
+        let recorded_unchanged = self
+            .entries
+            .get(&Self::key_for(task))
+            .map(|entry| entry.last_data_change == task.entry.last_data_change)
+            .unwrap_or(false);
+
+        recorded_unchanged && tokio::fs::metadata(&task.output_path).await.is_ok()
+    }
+
+    pub fn record(&mut self, task: &DownloadTask) {
+        self.entries.insert(
+            Self::key_for(task),
+            ManifestEntry {
+                last_data_change: task.entry.last_data_change.clone(),
+                last_structural_change: task.entry.last_structural_change.clone(),
+            },
+        );
+    }
+
+    pub async fn save(&self) -> Result<(), DownloaderError> {
+        let mut wtr = WriterBuilder::new().from_path(&self.path)?;
+
+        wtr.write_record(&["Key", "LastDataChange", "LastStructuralChange"])?;
+        for (key, entry) in &self.entries {
+            wtr.write_record(&[
+                key.clone(),
+                entry.last_data_change.clone(),
+                entry.last_structural_change.clone(),
+            ])?;
+        }
+
+        wtr.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+/// Token-bucket limiter enforcing a single, global byte-rate ceiling across
+/// all concurrent downloads. Cloning a `RateLimiter` shares the same bucket.
+#[derive(Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+    rate: u64,
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter that refills at `rate` bytes/second.
+    pub fn new(rate: u64) -> Self {
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: rate as f64,
+                last_refill: Instant::now(),
+            })),
+            rate,
+        }
+    }
+
+    /// Blocks until `amount` bytes' worth of tokens are available, then
+    /// spends them, drawing it down in burst-sized pieces if needed.
+    pub async fn acquire(&self, amount: u64) {
+        let mut remaining = amount;
+
+        while remaining > 0 {
+            let piece = remaining.min(self.rate.max(1));
+            self.acquire_piece(piece).await;
+            remaining -= piece;
+        }
+    }
+
+    async fn acquire_piece(&self, amount: u64) {
+        let amount = amount as f64;
+
+        loop {
+            let wait_for = {
+                let mut bucket = self.bucket.lock().await;
+                bucket.refill(self.rate);
+
+                if bucket.tokens >= amount {
+                    bucket.tokens -= amount;
+                    return;
+                }
+
+                let deficit = amount - bucket.tokens;
+                Duration::from_secs_f64(deficit / self.rate as f64)
+            };
+
+            sleep(wait_for).await;
+        }
+    }
+}
+
+impl Bucket {
+    fn refill(&mut self, rate: u64) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.last_refill = now;
+
+        let burst_capacity = rate as f64;
+        self.tokens = (self.tokens + elapsed * rate as f64).min(burst_capacity);
+    }
+}
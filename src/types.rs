@@ -37,12 +37,16 @@ pub struct DownloadReport {
     pub bytes_downloaded: u64,
     pub duration: Duration,
     pub error: Option<String>,
+    /// Number of attempts made, including the initial try.
+    pub attempts: u32,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum DownloadStatus {
     Success,
     Failed,
+    /// Skipped because the manifest already had this file's `last_data_change`.
+    Skipped,
 }
 
 #[derive(Debug)]
@@ -50,6 +54,7 @@ pub struct DownloadSummary {
     pub total_downloads: usize,
     pub successful_downloads: usize,
     pub failed_downloads: usize,
+    pub skipped_downloads: usize,
     pub total_bytes_downloaded: u64,
     pub total_duration: Duration,
     pub reports: Vec<DownloadReport>,